@@ -0,0 +1,38 @@
+//! Networking primitives
+//!
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use {io, Poll};
+
+mod tcp;
+
+pub use self::tcp::{TcpStream, TcpListener, Shutdown};
+
+#[cfg(unix)]
+mod unix;
+
+#[cfg(unix)]
+pub use self::unix::{UnixStream, UnixListener, UnixDatagram};
+
+#[derive(Clone, Debug)]
+struct SelectorId {
+    id: Arc<AtomicUsize>,
+}
+
+impl SelectorId {
+    fn new() -> SelectorId {
+        SelectorId { id: Arc::new(AtomicUsize::new(0)) }
+    }
+
+    fn associate_selector(&self, poll: &Poll) -> io::Result<()> {
+        let selector_id = poll.selector_id();
+
+        if 0 == self.id.compare_and_swap(0, selector_id, Ordering::SeqCst) ||
+            self.id.load(Ordering::SeqCst) == selector_id {
+            Ok(())
+        } else {
+            Err(io::Error::new(io::ErrorKind::Other, "socket already registered"))
+        }
+    }
+}
@@ -2,7 +2,9 @@
 
 use std::io::{Read, Write};
 use std::net::{self, SocketAddr, SocketAddrV4, SocketAddrV6, Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
 
+use iovec::IoVec;
 use net2::TcpBuilder;
 
 use {io, sys, Evented, Ready, Poll, PollOpt, Token};
@@ -132,23 +134,50 @@ impl TcpStream {
     /// On Windows, this will set the `SIO_KEEPALIVE_VALS` option.
     ///
     /// If `None` is specified then keepalive messages are disabled, otherwise
-    /// the number of milliseconds specified will be the time to remain idle
-    /// before sending a TCP keepalive probe.
+    /// the duration specified will be the time to remain idle before sending a
+    /// TCP keepalive probe.
     ///
-    /// Some platforms specify this value in seconds, so sub-second millisecond
+    /// Some platforms specify this value in seconds, so sub-second
     /// specifications may be omitted.
+    pub fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+        self.sys.set_keepalive(keepalive)
+    }
+
+    /// Returns whether keepalive messages are enabled on this socket, and if so
+    /// the time to remain idle before sending a keepalive probe.
+    ///
+    /// For more information about this option, see [`set_keepalive`][link].
+    ///
+    /// [link]: #method.set_keepalive
+    pub fn keepalive(&self) -> io::Result<Option<Duration>> {
+        self.sys.keepalive()
+    }
+
+    /// Sets whether keepalive messages are enabled to be sent on this socket.
+    ///
+    /// For more information about this option, see [`set_keepalive`][link].
+    ///
+    /// [link]: #method.set_keepalive
+    #[deprecated(since = "0.6.5", note = "use set_keepalive instead")]
+    #[doc(hidden)]
     pub fn set_keepalive_ms(&self, keepalive: Option<u32>) -> io::Result<()> {
-        self.sys.set_keepalive_ms(keepalive)
+        self.set_keepalive(keepalive.map(|ms| Duration::from_millis(ms as u64)))
     }
 
     /// Returns whether keepalive messages are enabled on this socket, and if so
     /// the amount of milliseconds between them.
     ///
-    /// For more information about this option, see [`set_keepalive_ms`][link].
+    /// For more information about this option, see [`set_keepalive`][link].
     ///
-    /// [link]: #method.set_keepalive_ms
+    /// [link]: #method.set_keepalive
+    #[deprecated(since = "0.6.5", note = "use keepalive instead")]
+    #[doc(hidden)]
     pub fn keepalive_ms(&self) -> io::Result<Option<u32>> {
-        self.sys.keepalive_ms()
+        self.keepalive().map(|keepalive| {
+            keepalive.map(|d| {
+                d.as_secs() as u32 * 1_000 + d.subsec_nanos() / 1_000_000
+            })
+        })
     }
 
     /// Sets the value for the `IP_TTL` option on this socket.
@@ -176,6 +205,82 @@ impl TcpStream {
     pub fn take_error(&self) -> io::Result<Option<io::Error>> {
         self.sys.take_error()
     }
+
+    /// Read in a list of buffers all at once.
+    ///
+    /// This operation will attempt to read bytes from this socket and place
+    /// them into the list of buffers provided, filling each buffer in order
+    /// (as in `readv`). Much like the `Read` implementation below, a short
+    /// count indicates the kernel only had that many bytes available; an
+    /// empty `bufs` slice returns `Ok(0)` without issuing a syscall.
+    ///
+    /// This function is the same as `Read::read` except that it reads into a
+    /// slice of buffers instead of one buffer, allowing a caller to fill
+    /// multiple non-contiguous chunks with a single call.
+    pub fn read_bufs(&self, bufs: &mut [&mut IoVec]) -> io::Result<usize> {
+        self.sys.readv(bufs)
+    }
+
+    /// Write a list of buffers all at once.
+    ///
+    /// This operation will attempt to write a list of byte buffers to this
+    /// socket in a single syscall (as in `writev`), rather than issuing a
+    /// separate `write` for each one. A short count indicates the kernel
+    /// accepted only a prefix of the buffers provided, following the same
+    /// partial-write semantics as the `Write` implementation below.
+    pub fn write_bufs(&self, bufs: &[&IoVec]) -> io::Result<usize> {
+        self.sys.writev(bufs)
+    }
+
+    /// Sets the value of the `SO_LINGER` option on this socket.
+    ///
+    /// This value controls how the socket's `close` behaves when there is
+    /// unsent data. A value of `None` disables the `SO_LINGER` option, while
+    /// `Some(duration)` causes `close` to block for up to `duration` while
+    /// the unsent data is flushed out.
+    pub fn set_linger(&self, dur: Option<Duration>) -> io::Result<()> {
+        self.sys.set_linger(dur)
+    }
+
+    /// Gets the value of the `SO_LINGER` option on this socket.
+    ///
+    /// For more information about this option, see [`set_linger`][link].
+    ///
+    /// [link]: #method.set_linger
+    pub fn linger(&self) -> io::Result<Option<Duration>> {
+        self.sys.linger()
+    }
+
+    /// Sets the size of the socket's receive buffer (`SO_RCVBUF`).
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.sys.set_recv_buffer_size(size)
+    }
+
+    /// Gets the size of the socket's receive buffer (`SO_RCVBUF`).
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.sys.recv_buffer_size()
+    }
+
+    /// Sets the size of the socket's send buffer (`SO_SNDBUF`).
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.sys.set_send_buffer_size(size)
+    }
+
+    /// Gets the size of the socket's send buffer (`SO_SNDBUF`).
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.sys.send_buffer_size()
+    }
+
+    /// Receives data on the socket from the remote address to which it is
+    /// connected, without removing that data from the queue.
+    ///
+    /// On success, returns the number of bytes peeked. Successive calls
+    /// return the same data. This is accomplished by passing `MSG_PEEK` as a
+    /// flag to the underlying `recv` system call, and follows the same
+    /// `WouldBlock` readiness contract as `read`.
+    pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.sys.peek(buf)
+    }
 }
 
 fn inaddr_any(other: &SocketAddr) -> SocketAddr {
@@ -292,6 +397,20 @@ impl TcpListener {
         })
     }
 
+    /// Creates a new `TcpListener` by issuing `listen` on a `net2::TcpBuilder`
+    /// that has already been bound to an address.
+    ///
+    /// This allows fine-grained control over the socket before it starts
+    /// listening: options such as `SO_REUSEPORT` (via net2's
+    /// `UnixTcpBuilderExt::reuse_port` on Unix) only take effect when set on
+    /// the builder before it is bound, so callers that want several
+    /// `TcpListener`s load-balanced across the same address by the kernel
+    /// must configure `builder` accordingly before passing it here.
+    pub fn from_builder(builder: TcpBuilder, addr: &SocketAddr) -> io::Result<TcpListener> {
+        let listener = try!(builder.listen(1024));
+        TcpListener::from_listener(listener, addr)
+    }
+
     /// Creates a new `TcpListener` from an instance of a
     /// `std::net::TcpListener` type.
     ///
@@ -384,6 +503,57 @@ impl TcpListener {
         self.sys.only_v6()
     }
 
+    /// Gets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// `SO_REUSEPORT` only takes effect when set on the `net2::TcpBuilder`
+    /// before the listening socket is bound (see [`from_builder`][link]); it
+    /// cannot be toggled on a `TcpListener` after `listen` has been called,
+    /// so no `set_reuse_port` is provided here.
+    ///
+    /// [link]: #method.from_builder
+    pub fn reuse_port(&self) -> io::Result<bool> {
+        self.sys.reuse_port()
+    }
+
+    /// Sets the value of the `SO_LINGER` option on this socket.
+    ///
+    /// This value controls how the socket's `close` behaves when there is
+    /// unsent data. A value of `None` disables the `SO_LINGER` option, while
+    /// `Some(duration)` causes `close` to block for up to `duration` while
+    /// the unsent data is flushed out.
+    pub fn set_linger(&self, dur: Option<Duration>) -> io::Result<()> {
+        self.sys.set_linger(dur)
+    }
+
+    /// Gets the value of the `SO_LINGER` option on this socket.
+    ///
+    /// For more information about this option, see [`set_linger`][link].
+    ///
+    /// [link]: #method.set_linger
+    pub fn linger(&self) -> io::Result<Option<Duration>> {
+        self.sys.linger()
+    }
+
+    /// Sets the size of the socket's receive buffer (`SO_RCVBUF`).
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.sys.set_recv_buffer_size(size)
+    }
+
+    /// Gets the size of the socket's receive buffer (`SO_RCVBUF`).
+    pub fn recv_buffer_size(&self) -> io::Result<usize> {
+        self.sys.recv_buffer_size()
+    }
+
+    /// Sets the size of the socket's send buffer (`SO_SNDBUF`).
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.sys.set_send_buffer_size(size)
+    }
+
+    /// Gets the size of the socket's send buffer (`SO_SNDBUF`).
+    pub fn send_buffer_size(&self) -> io::Result<usize> {
+        self.sys.send_buffer_size()
+    }
+
     /// Get the value of the `SO_ERROR` option on this socket.
     ///
     /// This will retrieve the stored error in the underlying socket, clearing